@@ -8,8 +8,10 @@ use crate::{
 use bracket_color::prelude::RGB;
 use bracket_geometry::prelude::{Point, Rect};
 use std::any::Any;
+use std::collections::HashSet;
 use std::convert::TryInto;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// A display console, used internally to provide console render support.
 /// Public in case you want to play with it, or access it directly.
@@ -48,8 +50,22 @@ impl Default for BTermInternal {
 unsafe impl Send for BTermInternal {}
 unsafe impl Sync for BTermInternal {}
 
+/// The stage of a touch gesture, as reported by a touch-capable backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TouchPhase {
+    Start,
+    Move,
+    End,
+}
+
 lazy_static! {
     pub(crate) static ref BACKEND_INTERNAL: Mutex<BTermInternal> = Mutex::new(BTermInternal::new());
+
+    /// Pixel size queued by the platform back-end's event pump as the window is
+    /// resized, decoupled from the tick cadence - see `BTerm::queue_resize` and
+    /// `main_loop`. Only the latest size matters once it's applied, so this holds at
+    /// most one.
+    static ref QUEUED_RESIZE: Mutex<Option<(u32, u32)>> = Mutex::new(None);
 }
 
 /// A BTerm context.
@@ -62,6 +78,7 @@ pub struct BTerm {
     pub active_console: usize,
     pub key: Option<VirtualKeyCode>,
     pub mouse_pos: (i32, i32),
+    pub mouse_wheel: (f32, f32),
     pub left_click: bool,
     pub shift: bool,
     pub control: bool,
@@ -70,6 +87,25 @@ pub struct BTerm {
     pub quitting: bool,
     pub post_scanlines: bool,
     pub post_screenburn: bool,
+    /// Keys that transitioned from up to down this frame.
+    pub pressed_keys: Vec<VirtualKeyCode>,
+    /// Keys that transitioned from down to up this frame.
+    pub released_keys: Vec<VirtualKeyCode>,
+    /// Every key that is currently held down, updated every frame.
+    pub held_keys: HashSet<VirtualKeyCode>,
+    /// Mouse buttons that transitioned from up to down this frame.
+    pub pressed_mouse: Vec<usize>,
+    /// Mouse buttons that transitioned from down to up this frame.
+    pub released_mouse: Vec<usize>,
+    /// Every mouse button that is currently held down, updated every frame - the
+    /// mouse-button equivalent of `held_keys`, since `left_click` is a one-shot flag
+    /// that only reads true on the tick the button went down.
+    pub held_mouse: HashSet<usize>,
+    /// Accumulated Unicode text input for the current frame, taken from the
+    /// platform's character events rather than reconstructed from keycodes.
+    pub text_input: String,
+    /// The tick cadence `main_loop` was started with (`InitHints::tick_rate_hz`).
+    pub tick_rate_hz: Option<u32>,
 }
 
 impl BTerm {
@@ -254,6 +290,80 @@ impl BTerm {
         xp
     }
 
+    /// Rasterizes the entire console stack (every registered layer, honoring
+    /// `register_console_no_bg` transparency) into a flat RGBA8 buffer the same
+    /// size as the window, using each console's registered `Font` glyph atlas.
+    /// Unlike `to_xp_file`, which only captures glyph/color data, this produces the
+    /// actual rendered pixels. It reads only CPU-side console and font data, so no
+    /// window or GPU context is required, which suits CI golden-image tests of drawing
+    /// routines or generating documentation art. `InitHints` has no headless option.
+    pub fn capture_frame(&self) -> (u32, u32, Vec<u8>) {
+        let bi = BACKEND_INTERNAL.lock().unwrap();
+        let width = self.width_pixels as usize;
+        let height = self.height_pixels as usize;
+        let mut buffer = vec![0u8; width * height * 4];
+
+        for cons in bi.consoles.iter() {
+            let layer = cons.console.to_xp_layer();
+            let font = &bi.fonts[cons.font_index];
+            let (tile_w, tile_h) = font.tile_size;
+            let no_bg = cons.shader_index == 1;
+
+            // `set_offset`/`set_scale` reposition and zoom a console on screen; honor
+            // both here so a headless capture matches what would actually be on-screen.
+            let (offset_x, offset_y) = cons.console.get_offset();
+            let (scale, scale_cx, scale_cy) = cons.console.get_scale();
+            let scale = if scale.abs() < f32::EPSILON { 1.0 } else { scale };
+            let dest_tile_w = ((tile_w as f32 * scale).round().max(1.0)) as i64;
+            let dest_tile_h = ((tile_h as f32 * scale).round().max(1.0)) as i64;
+
+            for cy in 0..layer.height {
+                for cx in 0..layer.width {
+                    let cell = &layer.cells[cx + cy * layer.width];
+                    let glyph_mask = font.glyph_mask(cell.ch as u8);
+
+                    let (screen_px0, screen_py0) = screen_tile_origin(
+                        (cx, cy),
+                        (tile_w, tile_h),
+                        (offset_x, offset_y),
+                        scale,
+                        (scale_cx, scale_cy),
+                    );
+
+                    for dy in 0..dest_tile_h {
+                        for dx in 0..dest_tile_w {
+                            let px = screen_px0 + dx;
+                            let py = screen_py0 + dy;
+                            if px < 0 || py < 0 || px as usize >= width || py as usize >= height {
+                                continue;
+                            }
+                            // Nearest-neighbor sample back into the unscaled glyph mask.
+                            let gx = ((dx as f32 / scale) as usize).min(tile_w as usize - 1);
+                            let gy = ((dy as f32 / scale) as usize).min(tile_h as usize - 1);
+                            let covered = glyph_mask[gx + gy * tile_w as usize] > 0;
+                            let color = if covered {
+                                Some(cell.fg)
+                            } else if !no_bg {
+                                Some(cell.bg)
+                            } else {
+                                None
+                            };
+                            if let Some(color) = color {
+                                let idx = (px as usize + py as usize * width) * 4;
+                                buffer[idx] = (color.r * 255.0) as u8;
+                                buffer[idx + 1] = (color.g * 255.0) as u8;
+                                buffer[idx + 2] = (color.b * 255.0) as u8;
+                                buffer[idx + 3] = 255;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        (self.width_pixels, self.height_pixels, buffer)
+    }
+
     /// Enable scanlines post-processing effect.
     pub fn with_post_scanlines(&mut self, with_burn: bool) {
         self.post_scanlines = true;
@@ -263,6 +373,13 @@ impl BTerm {
     /// Internal: mark a key press
     pub(crate) fn on_key(&mut self, key: VirtualKeyCode, scan_code: u32, pressed: bool) {
         self.key = Some(key);
+        if pressed {
+            self.pressed_keys.push(key);
+            self.held_keys.insert(key);
+        } else {
+            self.released_keys.push(key);
+            self.held_keys.remove(&key);
+        }
         let mut input = INPUT.lock().unwrap();
         if pressed {
             input.on_key_down(key, scan_code);
@@ -276,11 +393,35 @@ impl BTerm {
         });
     }
 
-    /// Internal: mark a mouse press
+    /// Internal: record a Unicode character typed this frame (shift/compose/emoji aware).
+    pub(crate) fn on_char(&mut self, c: char) {
+        self.text_input.push(c);
+        INPUT.lock().unwrap().push_event(BEvent::Character { c });
+    }
+
+    /// Internal: record a mouse wheel scroll delta.
+    pub(crate) fn on_mouse_wheel(&mut self, x: f32, y: f32) {
+        self.mouse_wheel = (x, y);
+        INPUT
+            .lock()
+            .unwrap()
+            .push_event(BEvent::MouseWheel { delta: (x, y) });
+    }
+
+    /// Internal: mark a mouse press. `left_click` is a one-shot "just pressed" flag for
+    /// button 0, so it only goes true on the press edge - see `pressed_mouse` for a
+    /// general per-button equivalent, and `held_mouse` for "is it down right now".
     pub(crate) fn on_mouse_button(&mut self, button_num: usize, pressed: bool) {
-        if button_num == 0 {
+        if button_num == 0 && pressed {
             self.left_click = true;
         }
+        if pressed {
+            self.pressed_mouse.push(button_num);
+            self.held_mouse.insert(button_num);
+        } else {
+            self.released_mouse.push(button_num);
+            self.held_mouse.remove(&button_num);
+        }
         let mut input = INPUT.lock().unwrap();
         if pressed {
             input.on_mouse_button_down(button_num);
@@ -293,13 +434,44 @@ impl BTerm {
         });
     }
 
+    /// Internal: map a touch event onto the existing mouse pipeline, so console code
+    /// that only knows about `mouse_point`/`left_click`/`pressed_mouse`/`held_mouse`
+    /// keeps working on touch-only devices. `touch_id` distinguishes simultaneous
+    /// touches for multi-touch gestures and is carried on the emitted `BEvent`; it is
+    /// not used for anything else here, so every touch, whatever its id, drives
+    /// `mouse_pos` and button 0.
+    ///
+    /// `Start`/`End` go through `on_mouse_button` rather than duplicating its effects,
+    /// so `held_mouse`/`pressed_mouse`/`released_mouse` (and `INPUT`'s button-down
+    /// tracking) stay correct for touch input too - that's what lets `gui::slider`
+    /// keep a drag focused across a `TouchPhase::Move` the same way it does for a held
+    /// physical mouse button.
+    // TODO: an actual touch-capable backend (calling this from an Android/mobile HAL
+    // path, and honoring `InitHints::fullscreen`/`orientation`) still needs to be
+    // wired up - this is only the BTerm-side event mapping.
+    pub(crate) fn on_touch(&mut self, touch_id: u64, x: f64, y: f64, phase: TouchPhase) {
+        self.on_mouse_position(x, y);
+        match phase {
+            TouchPhase::Start => {
+                INPUT.lock().unwrap().push_event(BEvent::TouchStart { touch_id, x, y });
+                self.on_mouse_button(0, true);
+            }
+            TouchPhase::Move => {
+                INPUT.lock().unwrap().push_event(BEvent::TouchMove { touch_id, x, y });
+            }
+            TouchPhase::End => {
+                INPUT.lock().unwrap().push_event(BEvent::TouchEnd { touch_id, x, y });
+                self.on_mouse_button(0, false);
+            }
+        }
+    }
+
     /// Internal: mark mouse position changes
     pub(crate) fn on_mouse_position(&mut self, x: f64, y: f64) {
         let bi = BACKEND_INTERNAL.lock().unwrap();
         self.mouse_pos = (x as i32, y as i32);
         let mut input = INPUT.lock().unwrap();
         input.on_mouse_pixel_position(x, y);
-        // TODO: Console cascade!
         for (i, cons) in bi.consoles.iter().enumerate() {
             let max_sizes = cons.console.get_char_size();
 
@@ -319,6 +491,67 @@ impl BTerm {
         }
     }
 
+    /// Works out which console layer is actually under the mouse cursor, and the tile
+    /// coordinate within that console's character space. Walks the console stack from
+    /// the top down, so a higher layer always wins - unless it was registered with
+    /// `register_console_no_bg` and the cell under the cursor is empty (space/zero glyph),
+    /// in which case the cursor "falls through" to the console beneath it.
+    pub fn hovered_console(&self) -> Option<(usize, Point)> {
+        let bi = BACKEND_INTERNAL.lock().unwrap();
+        for (i, cons) in bi.consoles.iter().enumerate().rev() {
+            let max_sizes = cons.console.get_char_size();
+            let tile = Point::new(
+                iclamp(
+                    self.mouse_pos.0 * max_sizes.0 as i32 / i32::max(1, self.width_pixels as i32),
+                    0,
+                    max_sizes.0 as i32 - 1,
+                ),
+                iclamp(
+                    self.mouse_pos.1 * max_sizes.1 as i32 / i32::max(1, self.height_pixels as i32),
+                    0,
+                    max_sizes.1 as i32 - 1,
+                ),
+            );
+
+            let is_pass_through = cons.shader_index == 1
+                && !cons.console.is_cell_opaque(tile.x, tile.y);
+            if !is_pass_through {
+                return Some((i, tile));
+            }
+        }
+        None
+    }
+
+    /// Called by the platform back-end's event pump - on its own thread/loop,
+    /// independent of the tick cadence - whenever the window is resized. Queues the
+    /// size rather than applying it immediately, so a whole drag-resize's worth of OS
+    /// events collapses into a single `resize_pixels` call on the next tick instead of
+    /// one per event.
+    pub fn queue_resize(width: u32, height: u32) {
+        *QUEUED_RESIZE.lock().unwrap() = Some((width, height));
+    }
+
+    /// Internal: applies the most recently queued resize, if any. Called once per
+    /// tick by `main_loop`, between ticks rather than mid-tick.
+    pub(crate) fn apply_queued_resize(&mut self) {
+        if let Some((width, height)) = QUEUED_RESIZE.lock().unwrap().take() {
+            self.resize_pixels(width, height);
+        }
+    }
+
+    /// Internal: called by `main_loop` at the start of each tick, before
+    /// `GameState::tick`, to clear the per-frame input snapshots (pressed/released
+    /// keys and buttons, scroll delta, and accumulated text).
+    pub(crate) fn new_frame(&mut self) {
+        self.pressed_keys.clear();
+        self.released_keys.clear();
+        self.pressed_mouse.clear();
+        self.released_mouse.clear();
+        self.mouse_wheel = (0.0, 0.0);
+        self.text_input.clear();
+        self.left_click = false;
+    }
+
     /// Internal: record an event from the HAL back-end
     #[allow(dead_code)]
     pub(crate) fn on_event(&mut self, event: BEvent) {
@@ -332,6 +565,11 @@ impl Console for BTerm {
         bi.consoles[self.active_console].console.get_char_size()
     }
 
+    /// Applies a pixel resize.
+    // TODO: mobile backends recreate the rendering surface across suspend/resume rather
+    // than resizing it in place - this needs to re-upload fonts/shaders from their
+    // already-loaded CPU-side data in `BACKEND_INTERNAL` once the mobile HAL path exists,
+    // instead of assuming the old GPU resources are still valid.
     fn resize_pixels(&mut self, width: u32, height: u32) {
         self.width_pixels = width;
         self.height_pixels = height;
@@ -349,6 +587,11 @@ impl Console for BTerm {
             .console
             .at(x, y)
     }
+    fn is_cell_opaque(&self, x: i32, y: i32) -> bool {
+        BACKEND_INTERNAL.lock().unwrap().consoles[self.active_console]
+            .console
+            .is_cell_opaque(x, y)
+    }
     fn cls(&mut self) {
         BACKEND_INTERNAL.lock().unwrap().consoles[self.active_console]
             .console
@@ -494,21 +737,59 @@ impl Console for BTerm {
             .console
             .set_offset(x, y);
     }
+    fn get_offset(&self) -> (f32, f32) {
+        BACKEND_INTERNAL.lock().unwrap().consoles[self.active_console]
+            .console
+            .get_offset()
+    }
     fn set_scale(&mut self, scale: f32, center_x: i32, center_y: i32) {
         BACKEND_INTERNAL.lock().unwrap().consoles[self.active_console]
             .console
             .set_scale(scale, center_x, center_y);
     }
+    fn get_scale(&self) -> (f32, i32, i32) {
+        BACKEND_INTERNAL.lock().unwrap().consoles[self.active_console]
+            .console
+            .get_scale()
+    }
 
     fn as_any(&self) -> &dyn Any {
         self
     }
 }
 
-/// Runs the BTerm application, calling into the provided gamestate handler every tick.
-pub fn main_loop<GS: GameState>(bterm: BTerm, gamestate: GS) -> Result<()> {
-    super::hal::main_loop(bterm, gamestate)?;
-    Ok(())
+/// Runs the BTerm application, calling into the provided gamestate handler on the
+/// cadence set by `InitHints::tick_rate_hz` (or back to back with no wait, if unset),
+/// decoupled from window resize traffic: the platform back-end queues resizes via
+/// `queue_resize` as they arrive on its own event-pump thread/loop, and we only drain
+/// the latest one here, once per tick, instead of resizing (and reallocating GPU-side
+/// buffers) on every single OS resize event.
+// TODO: the platform-specific event pump (winit, crossterm, ...) that feeds input
+// into BTerm from its own thread/loop still belongs in the HAL back-ends, which
+// aren't part of this source excerpt - see `prelude`'s doc comment.
+pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> Result<()> {
+    let tick_budget = bterm
+        .tick_rate_hz
+        .map(|hz| Duration::from_secs_f64(1.0 / f64::from(hz.max(1))));
+
+    loop {
+        let tick_started = Instant::now();
+
+        bterm.new_frame();
+        bterm.apply_queued_resize();
+
+        gamestate.tick(&mut bterm);
+        if bterm.quitting {
+            return Ok(());
+        }
+
+        if let Some(budget) = tick_budget {
+            let elapsed = tick_started.elapsed();
+            if elapsed < budget {
+                std::thread::sleep(budget - elapsed);
+            }
+        }
+    }
 }
 
 /// For A-Z menus, translates the keys A through Z into 0..25
@@ -549,9 +830,130 @@ fn iclamp(val: i32, min: i32, max: i32) -> i32 {
     i32::max(min, i32::min(val, max))
 }
 
+/// Maps a console-space tile coordinate to the top-left screen pixel it should be
+/// rasterized at, honoring `set_offset` (a tile-space scroll) and `set_scale` (a zoom
+/// factor centered on a tile). Used by `capture_frame` so a headless capture matches
+/// what `set_offset`/`set_scale` would actually put on screen.
+fn screen_tile_origin(
+    tile: (usize, usize),
+    tile_size: (u32, u32),
+    offset: (f32, f32),
+    scale: f32,
+    scale_center: (i32, i32),
+) -> (i64, i64) {
+    let (tile_w, tile_h) = tile_size;
+    let center_px = scale_center.0 as f32 * tile_w as f32;
+    let center_py = scale_center.1 as f32 * tile_h as f32;
+    let base_px = (tile.0 as f32 + offset.0) * tile_w as f32;
+    let base_py = (tile.1 as f32 + offset.1) * tile_h as f32;
+    (
+        (center_px + (base_px - center_px) * scale).round() as i64,
+        (center_py + (base_py - center_py) * scale).round() as i64,
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use super::iclamp;
+    use super::{iclamp, screen_tile_origin, BTerm, TouchPhase};
+
+    fn test_bterm() -> BTerm {
+        BTerm {
+            width_pixels: 800,
+            height_pixels: 600,
+            fps: 0.0,
+            frame_time_ms: 0.0,
+            active_console: 0,
+            key: None,
+            mouse_pos: (0, 0),
+            mouse_wheel: (0.0, 0.0),
+            left_click: false,
+            shift: false,
+            control: false,
+            alt: false,
+            web_button: None,
+            quitting: false,
+            post_scanlines: false,
+            post_screenburn: false,
+            pressed_keys: Vec::new(),
+            released_keys: Vec::new(),
+            held_keys: Default::default(),
+            pressed_mouse: Vec::new(),
+            released_mouse: Vec::new(),
+            held_mouse: Default::default(),
+            text_input: String::new(),
+            tick_rate_hz: None,
+        }
+    }
+
+    #[test]
+    // QUEUED_RESIZE is process-global, so this covers both behaviors in one test
+    // rather than risking two tests racing on it under cargo's parallel test runner.
+    fn queue_resize_collapses_a_burst_and_applies_once_between_ticks() {
+        let mut bterm = test_bterm();
+        assert_eq!((bterm.width_pixels, bterm.height_pixels), (800, 600));
+
+        BTerm::queue_resize(640, 480);
+        BTerm::queue_resize(1024, 768);
+        bterm.apply_queued_resize();
+        assert_eq!(
+            (bterm.width_pixels, bterm.height_pixels),
+            (1024, 768),
+            "a burst of resizes should collapse to the latest size"
+        );
+
+        // Nothing queued this time - the previous size is left alone.
+        bterm.apply_queued_resize();
+        assert_eq!((bterm.width_pixels, bterm.height_pixels), (1024, 768));
+    }
+
+    #[test]
+    fn on_mouse_button_sets_left_click_on_press_only() {
+        let mut bterm = test_bterm();
+        bterm.on_mouse_button(0, true);
+        assert!(bterm.left_click);
+
+        bterm.new_frame();
+        bterm.on_mouse_button(0, false);
+        assert!(
+            !bterm.left_click,
+            "left_click should stay false on a release, not fire a second time"
+        );
+    }
+
+    #[test]
+    fn on_mouse_button_tracks_held_and_pressed_state() {
+        let mut bterm = test_bterm();
+        bterm.on_mouse_button(0, true);
+        assert!(bterm.held_mouse.contains(&0));
+        assert!(bterm.pressed_mouse.contains(&0));
+
+        bterm.new_frame();
+        bterm.on_mouse_button(0, false);
+        assert!(!bterm.held_mouse.contains(&0));
+        assert!(bterm.released_mouse.contains(&0));
+    }
+
+    #[test]
+    fn on_touch_drives_held_mouse_like_a_physical_button() {
+        let mut bterm = test_bterm();
+        bterm.on_touch(0, 10.0, 10.0, TouchPhase::Start);
+        assert!(
+            bterm.held_mouse.contains(&0),
+            "a touch start should hold button 0, same as gui::slider expects from a mouse drag"
+        );
+
+        bterm.new_frame();
+        bterm.on_touch(0, 12.0, 11.0, TouchPhase::Move);
+        assert!(
+            bterm.held_mouse.contains(&0),
+            "a move between start and end must not drop the held button"
+        );
+
+        bterm.new_frame();
+        bterm.on_touch(0, 12.0, 11.0, TouchPhase::End);
+        assert!(!bterm.held_mouse.contains(&0));
+        assert!(bterm.released_mouse.contains(&0));
+    }
 
     #[test]
     // Tests that we make an RGB triplet at defaults and it is black.
@@ -560,4 +962,35 @@ mod tests {
         assert!(iclamp(5, 0, 2) == 2);
         assert!(iclamp(-5, 0, 2) == 0);
     }
+
+    #[test]
+    fn screen_tile_origin_with_no_offset_or_scale_is_identity() {
+        assert_eq!(
+            screen_tile_origin((2, 3), (8, 8), (0.0, 0.0), 1.0, (0, 0)),
+            (16, 24)
+        );
+    }
+
+    #[test]
+    fn screen_tile_origin_applies_offset() {
+        assert_eq!(
+            screen_tile_origin((2, 3), (8, 8), (1.0, -1.0), 1.0, (0, 0)),
+            (24, 16)
+        );
+    }
+
+    #[test]
+    fn screen_tile_origin_scales_around_center() {
+        // Tile (2, 2) is the scale center, so it stays put when zoomed.
+        assert_eq!(
+            screen_tile_origin((2, 2), (8, 8), (0.0, 0.0), 2.0, (2, 2)),
+            (16, 16)
+        );
+        // Tile (3, 2) is one tile-width to the right of center, so it moves twice as
+        // far from center once zoomed in.
+        assert_eq!(
+            screen_tile_origin((3, 2), (8, 8), (0.0, 0.0), 2.0, (2, 2)),
+            (32, 16)
+        );
+    }
 }
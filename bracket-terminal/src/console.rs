@@ -0,0 +1,53 @@
+//! The `Console` trait is the common drawing surface implemented by every console
+//! type (`SimpleConsole`, sparse/layered consoles, and `BTerm` itself as a
+//! pass-through to its active layer). Backing storage and rendering differ per
+//! implementation; this is what game code and the GUI widget layer actually draw
+//! against, and what `BTerm` walks when it cascades hit-testing or rasterizes a
+//! headless frame.
+use crate::prelude::{TextAlign, XpLayer};
+use bracket_color::prelude::RGB;
+use bracket_geometry::prelude::Rect;
+use std::any::Any;
+
+pub trait Console: Any + Send + Sync {
+    fn get_char_size(&self) -> (u32, u32);
+    fn resize_pixels(&mut self, width: u32, height: u32);
+    fn at(&self, x: i32, y: i32) -> usize;
+
+    /// Whether the cell at `(x, y)` is actually painted (a glyph other than space or
+    /// zero). Consoles
+    /// registered with `register_console_no_bg` use this so that cursor/tooltip
+    /// hit-testing can fall through an empty cell to whatever is layered underneath.
+    fn is_cell_opaque(&self, x: i32, y: i32) -> bool;
+
+    fn cls(&mut self);
+    fn cls_bg(&mut self, background: RGB);
+    fn print(&mut self, x: i32, y: i32, output: &str);
+    fn print_color(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, output: &str);
+    fn set(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, glyph: u8);
+    fn set_bg(&mut self, x: i32, y: i32, bg: RGB);
+    fn draw_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB);
+    fn draw_box_double(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB);
+    fn draw_hollow_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB);
+    fn draw_hollow_box_double(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB);
+    fn draw_bar_horizontal(&mut self, x: i32, y: i32, width: i32, n: i32, max: i32, fg: RGB, bg: RGB);
+    fn draw_bar_vertical(&mut self, x: i32, y: i32, height: i32, n: i32, max: i32, fg: RGB, bg: RGB);
+    fn fill_region(&mut self, target: Rect, glyph: u8, fg: RGB, bg: RGB);
+    fn print_centered(&mut self, y: i32, text: &str);
+    fn print_color_centered(&mut self, y: i32, fg: RGB, bg: RGB, text: &str);
+    fn print_centered_at(&mut self, x: i32, y: i32, text: &str);
+    fn print_color_centered_at(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: &str);
+    fn print_right(&mut self, x: i32, y: i32, text: &str);
+    fn print_color_right(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: &str);
+    fn printer(&mut self, x: i32, y: i32, output: &str, align: TextAlign, background: Option<RGB>);
+    fn to_xp_layer(&self) -> XpLayer;
+
+    fn set_offset(&mut self, x: f32, y: f32);
+    /// The current scroll offset, in tile units, as last set by `set_offset`.
+    fn get_offset(&self) -> (f32, f32);
+    fn set_scale(&mut self, scale: f32, center_x: i32, center_y: i32);
+    /// The current zoom factor and the tile it's centered on, as last set by `set_scale`.
+    fn get_scale(&self) -> (f32, i32, i32);
+
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,7 @@
+use crate::bterm::BTerm;
+
+/// Implemented by your game/application root. `main_loop` calls `tick` once per
+/// simulation step, on the cadence configured via `InitHints::tick_rate_hz`.
+pub trait GameState: 'static {
+    fn tick(&mut self, ctx: &mut BTerm);
+}
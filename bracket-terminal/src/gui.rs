@@ -0,0 +1,176 @@
+//! An optional immediate-mode widget layer built on top of the `Console` drawing API
+//! and the per-frame input now tracked on `BTerm`. Widgets are drawn and evaluated in
+//! the same call, every tick - there's no retained widget tree to keep in sync with
+//! your game state.
+//!
+//! Enable with the `gui` feature. Widgets render to `bterm.active_console`, so they
+//! compose naturally with layered `register_console_no_bg` consoles (draw your HUD
+//! widgets on a transparent layer on top of the game view).
+use crate::prelude::{BTerm, Console};
+use bracket_color::prelude::RGB;
+use bracket_geometry::prelude::Rect;
+
+/// Per-frame focus/hover bookkeeping for the widget layer. Create one alongside your
+/// `BTerm` and pass it to each widget call; widget identity is whatever `u64` id you
+/// pass in (e.g. a hash of the call site, or an explicit id you control).
+#[derive(Clone, Debug, Default)]
+pub struct GuiContext {
+    pub hovered: Option<u64>,
+    pub focused: Option<u64>,
+}
+
+impl GuiContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn hit_test(bterm: &BTerm, rect: Rect) -> bool {
+        let mouse = bterm.mouse_point();
+        rect.point_in_rect(mouse)
+    }
+}
+
+/// Draws a clickable button at `rect` with `label` centered in it, and returns `true`
+/// on the tick it was clicked.
+pub fn button(bterm: &mut BTerm, gui: &mut GuiContext, id: u64, rect: Rect, label: &str) -> bool {
+    let hovered = GuiContext::hit_test(bterm, rect);
+    if hovered {
+        gui.hovered = Some(id);
+    }
+    let (fg, bg) = if hovered {
+        (RGB::named(bracket_color::prelude::WHITE), RGB::named(bracket_color::prelude::GREY30))
+    } else {
+        (RGB::named(bracket_color::prelude::GREY70), RGB::named(bracket_color::prelude::GREY15))
+    };
+
+    bterm.draw_box(rect.x1, rect.y1, rect.width(), rect.height(), fg, bg);
+    bterm.print_color_centered_at(
+        rect.x1 + rect.width() / 2,
+        rect.y1 + rect.height() / 2,
+        fg,
+        bg,
+        label,
+    );
+
+    hovered && bterm.pressed_mouse.contains(&0)
+}
+
+/// Draws a checkbox with `label` and toggles `checked` when clicked this frame.
+/// Returns `true` if the value changed.
+pub fn checkbox(bterm: &mut BTerm, gui: &mut GuiContext, id: u64, rect: Rect, label: &str, checked: &mut bool) -> bool {
+    let mark = if *checked { "[x]" } else { "[ ]" };
+    let text = format!("{} {}", mark, label);
+    let hovered = GuiContext::hit_test(bterm, rect);
+    if hovered {
+        gui.hovered = Some(id);
+    }
+    bterm.print(rect.x1, rect.y1, &text);
+
+    let clicked = hovered && bterm.pressed_mouse.contains(&0);
+    if clicked {
+        *checked = !*checked;
+    }
+    clicked
+}
+
+/// Draws a horizontal slider over `rect` for `value` within `range`, and updates
+/// `value` while the left button is held after being pressed down on it - dragging
+/// is allowed to continue outside `rect` once started, the same as most sliders.
+/// Returns `true` if the value changed.
+pub fn slider(bterm: &mut BTerm, gui: &mut GuiContext, id: u64, rect: Rect, range: (f32, f32), value: &mut f32) -> bool {
+    let hovered = GuiContext::hit_test(bterm, rect);
+    if hovered {
+        gui.hovered = Some(id);
+    }
+
+    let width = rect.width().max(1);
+    let frac = ((*value - range.0) / (range.1 - range.0)).clamp(0.0, 1.0);
+    let fill = (frac * width as f32) as i32;
+    bterm.draw_hollow_box(rect.x1, rect.y1, rect.width(), rect.height(), RGB::named(bracket_color::prelude::GREY70), RGB::named(bracket_color::prelude::BLACK));
+    bterm.draw_bar_horizontal(rect.x1, rect.y1, width, fill, width, RGB::named(bracket_color::prelude::CYAN), RGB::named(bracket_color::prelude::GREY15));
+
+    if hovered && bterm.pressed_mouse.contains(&0) {
+        gui.focused = Some(id);
+    }
+
+    if gui.focused == Some(id) {
+        if !bterm.held_mouse.contains(&0) {
+            // The button came back up since we grabbed focus - release it, so a later,
+            // unrelated click elsewhere can't be mistaken for a continued drag.
+            gui.focused = None;
+            return false;
+        }
+
+        let mouse_x = bterm.mouse_point().x - rect.x1;
+        if let Some(new_value) = slider_drag_value(mouse_x, width, range, *value) {
+            *value = new_value;
+            return true;
+        }
+    }
+    false
+}
+
+/// Pure core of the slider drag update: given the pointer's x position within the
+/// track (can be outside `0..width`), returns the new value if it differs from
+/// `current`. Factored out so the math can be tested without a `BTerm`.
+fn slider_drag_value(pointer_x: i32, width: i32, range: (f32, f32), current: f32) -> Option<f32> {
+    let new_frac = (pointer_x as f32 / width as f32).clamp(0.0, 1.0);
+    let new_value = range.0 + new_frac * (range.1 - range.0);
+    if (new_value - current).abs() > f32::EPSILON {
+        Some(new_value)
+    } else {
+        None
+    }
+}
+
+/// Draws an editable text box backed by `text`; while focused (clicked into), the
+/// frame's `text_input` is appended and `Back` removes the last character. Returns
+/// `true` if the contents changed this frame.
+pub fn text_box(bterm: &mut BTerm, gui: &mut GuiContext, id: u64, rect: Rect, text: &mut String) -> bool {
+    let hovered = GuiContext::hit_test(bterm, rect);
+    if hovered {
+        gui.hovered = Some(id);
+    }
+    if hovered && bterm.pressed_mouse.contains(&0) {
+        gui.focused = Some(id);
+    }
+
+    let mut changed = false;
+    if gui.focused == Some(id) {
+        if !bterm.text_input.is_empty() {
+            text.push_str(&bterm.text_input);
+            changed = true;
+        }
+        if bterm.pressed_keys.contains(&crate::prelude::VirtualKeyCode::Back) {
+            text.pop();
+            changed = true;
+        }
+    }
+
+    bterm.draw_hollow_box(rect.x1, rect.y1, rect.width(), rect.height(), RGB::named(bracket_color::prelude::GREY70), RGB::named(bracket_color::prelude::BLACK));
+    bterm.print(rect.x1 + 1, rect.y1, text);
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::slider_drag_value;
+
+    #[test]
+    fn slider_drag_value_maps_pointer_across_range() {
+        assert_eq!(slider_drag_value(0, 10, (0.0, 100.0), 50.0), Some(0.0));
+        assert_eq!(slider_drag_value(10, 10, (0.0, 100.0), 50.0), Some(100.0));
+        assert_eq!(slider_drag_value(5, 10, (0.0, 100.0), 0.0), Some(50.0));
+    }
+
+    #[test]
+    fn slider_drag_value_clamps_out_of_bounds_pointer() {
+        assert_eq!(slider_drag_value(-5, 10, (0.0, 100.0), 50.0), Some(0.0));
+        assert_eq!(slider_drag_value(50, 10, (0.0, 100.0), 50.0), Some(100.0));
+    }
+
+    #[test]
+    fn slider_drag_value_returns_none_when_unchanged() {
+        assert_eq!(slider_drag_value(5, 10, (0.0, 100.0), 50.0), None);
+    }
+}
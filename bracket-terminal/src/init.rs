@@ -0,0 +1,82 @@
+/// Which screen orientation(s) a touch/mobile back-end should allow. Ignored on
+/// desktop back-ends, which don't rotate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Orientation {
+    Landscape,
+    Portrait,
+    /// Follow the device's rotation.
+    Any,
+}
+
+/// Hints passed to `BTerm::init_raw` to configure the window and game loop before
+/// it's created.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InitHints {
+    /// Target rate, in Hz, at which `main_loop` calls `GameState::tick`, decoupled
+    /// from the platform's event-pump/redraw rate. `None` (the default) sets no
+    /// pace: `main_loop` calls `tick` again as soon as the previous call returns.
+    pub tick_rate_hz: Option<u32>,
+    /// Request a fullscreen window/view. Mobile back-ends should treat this as
+    /// implied regardless of the value, since there's no windowed mode to fall back to.
+    pub fullscreen: bool,
+    /// Allowed orientation(s) on touch/mobile back-ends.
+    pub orientation: Orientation,
+}
+
+impl InitHints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `GameState::tick` at a fixed rate, independent of window resize/redraw
+    /// traffic. See `main_loop`.
+    pub fn with_tick_rate(mut self, hz: u32) -> Self {
+        self.tick_rate_hz = Some(hz);
+        self
+    }
+
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = fullscreen;
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+}
+
+impl Default for InitHints {
+    fn default() -> Self {
+        Self {
+            tick_rate_hz: None,
+            fullscreen: false,
+            orientation: Orientation::Any,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InitHints, Orientation};
+
+    #[test]
+    fn builder_methods_override_defaults() {
+        let hints = InitHints::new()
+            .with_tick_rate(60)
+            .with_fullscreen(true)
+            .with_orientation(Orientation::Landscape);
+
+        assert_eq!(hints.tick_rate_hz, Some(60));
+        assert!(hints.fullscreen);
+        assert_eq!(hints.orientation, Orientation::Landscape);
+    }
+
+    #[test]
+    fn defaults_keep_historical_behavior() {
+        let hints = InitHints::default();
+        assert_eq!(hints.tick_rate_hz, None);
+        assert!(!hints.fullscreen);
+        assert_eq!(hints.orientation, Orientation::Any);
+    }
+}
@@ -0,0 +1,109 @@
+//! The per-frame input event queue and button/key state tracking shared by all HAL
+//! back-ends. `BTerm` mirrors a slim per-frame snapshot of this onto itself for
+//! convenience; reach for `INPUT` directly when you need the full event history for
+//! a frame that `BTerm`'s snapshot doesn't capture (e.g. the order in which presses,
+//! releases and key events arrived within one tick).
+use crate::prelude::VirtualKeyCode;
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+
+/// A single input occurrence, pushed by the HAL back-end as OS events arrive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BEvent {
+    KeyboardInput {
+        key: VirtualKeyCode,
+        scan_code: u32,
+        pressed: bool,
+    },
+    MouseClick {
+        button: usize,
+        pressed: bool,
+    },
+    /// A Unicode character was typed, as reported by the platform's character events
+    /// rather than reconstructed from keycodes (so shift/compose/emoji all work).
+    Character {
+        c: char,
+    },
+    /// The mouse wheel scrolled by this (x, y) delta.
+    MouseWheel {
+        delta: (f32, f32),
+    },
+    TouchStart {
+        touch_id: u64,
+        x: f64,
+        y: f64,
+    },
+    TouchMove {
+        touch_id: u64,
+        x: f64,
+        y: f64,
+    },
+    TouchEnd {
+        touch_id: u64,
+        x: f64,
+        y: f64,
+    },
+}
+
+#[derive(Default)]
+pub struct Input {
+    events: VecDeque<BEvent>,
+    keys_down: HashSet<(VirtualKeyCode, u32)>,
+    mouse_buttons_down: HashSet<usize>,
+    mouse_pixel_position: (f64, f64),
+    mouse_tile_position: Vec<(i32, i32)>,
+}
+
+impl Input {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_event(&mut self, event: BEvent) {
+        self.events.push_back(event);
+    }
+
+    /// Drains the next queued event, in the order it was pushed.
+    pub fn pop(&mut self) -> Option<BEvent> {
+        self.events.pop_front()
+    }
+
+    pub(crate) fn on_key_down(&mut self, key: VirtualKeyCode, scan_code: u32) {
+        self.keys_down.insert((key, scan_code));
+    }
+
+    pub(crate) fn on_key_up(&mut self, key: VirtualKeyCode, scan_code: u32) {
+        self.keys_down.remove(&(key, scan_code));
+    }
+
+    pub(crate) fn on_mouse_button_down(&mut self, button: usize) {
+        self.mouse_buttons_down.insert(button);
+    }
+
+    pub(crate) fn on_mouse_button_up(&mut self, button: usize) {
+        self.mouse_buttons_down.remove(&button);
+    }
+
+    pub(crate) fn on_mouse_pixel_position(&mut self, x: f64, y: f64) {
+        self.mouse_pixel_position = (x, y);
+    }
+
+    pub(crate) fn on_mouse_tile_position(&mut self, console: usize, x: i32, y: i32) {
+        if console >= self.mouse_tile_position.len() {
+            self.mouse_tile_position.resize(console + 1, (0, 0));
+        }
+        self.mouse_tile_position[console] = (x, y);
+    }
+
+    pub fn is_key_pressed(&self, key: VirtualKeyCode) -> bool {
+        self.keys_down.iter().any(|(k, _)| *k == key)
+    }
+
+    pub fn is_mouse_button_pressed(&self, button: usize) -> bool {
+        self.mouse_buttons_down.contains(&button)
+    }
+}
+
+lazy_static! {
+    pub static ref INPUT: Mutex<Input> = Mutex::new(Input::new());
+}
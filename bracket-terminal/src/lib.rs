@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod bterm;
+pub mod console;
+pub mod game_state;
+pub mod init;
+pub mod input;
+pub mod prelude;
+pub mod simple_console;
+
+#[cfg(feature = "gui")]
+pub mod gui;
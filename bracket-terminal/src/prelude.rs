@@ -0,0 +1,17 @@
+//! Convenience re-exports for the pieces of the crate defined in this source tree.
+//!
+//! `font`, `Shader`, `VirtualKeyCode`, `XpFile`/`XpLayer`, `TextAlign`, `init_raw`, and
+//! the `hal`/`rex` backend modules are referenced throughout `bterm.rs` but are not
+//! part of this excerpt of the crate - they're expected to keep being re-exported here
+//! alongside the items below once they are.
+pub use crate::bterm::{
+    letter_to_option, main_loop, BTerm, BTermInternal, DisplayConsole, TouchPhase,
+};
+pub use crate::console::Console;
+pub use crate::game_state::GameState;
+pub use crate::init::{InitHints, Orientation};
+pub use crate::input::{BEvent, Input, INPUT};
+pub use crate::simple_console::SimpleConsole;
+
+#[cfg(feature = "gui")]
+pub use crate::gui::*;
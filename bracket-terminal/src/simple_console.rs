@@ -0,0 +1,258 @@
+//! The simplest `Console` implementation: a plain rectangular grid of glyph/fg/bg
+//! tiles, no sparse storage or dirty tracking. This is what the deprecated
+//! `init_simple8x8`/`init_simple8x16` helpers register, and a convenient baseline
+//! for anything that just needs `Console` to work (headless capture tests, the GUI
+//! widget layer, hit-testing).
+use crate::console::Console;
+use crate::prelude::{TextAlign, XpLayer};
+use bracket_color::prelude::RGB;
+use bracket_geometry::prelude::Rect;
+use std::any::Any;
+
+#[derive(Clone)]
+struct Tile {
+    glyph: u8,
+    fg: RGB,
+    bg: RGB,
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Self {
+            glyph: 32,
+            fg: RGB::from_f32(1.0, 1.0, 1.0),
+            bg: RGB::from_f32(0.0, 0.0, 0.0),
+        }
+    }
+}
+
+pub struct SimpleConsole {
+    width: u32,
+    height: u32,
+    tiles: Vec<Tile>,
+    offset: (f32, f32),
+    scale: (f32, i32, i32),
+}
+
+impl SimpleConsole {
+    pub fn init(width: u32, height: u32) -> Box<dyn Console> {
+        Box::new(Self {
+            width,
+            height,
+            tiles: vec![Tile::default(); (width * height) as usize],
+            offset: (0.0, 0.0),
+            scale: (1.0, 0, 0),
+        })
+    }
+
+    fn idx(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return None;
+        }
+        Some((x as u32 + y as u32 * self.width) as usize)
+    }
+}
+
+impl Console for SimpleConsole {
+    fn get_char_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn resize_pixels(&mut self, _width: u32, _height: u32) {}
+
+    fn at(&self, x: i32, y: i32) -> usize {
+        self.idx(x, y)
+            .map(|i| self.tiles[i].glyph as usize)
+            .unwrap_or(0)
+    }
+
+    fn is_cell_opaque(&self, x: i32, y: i32) -> bool {
+        self.idx(x, y)
+            .map(|i| self.tiles[i].glyph != 0 && self.tiles[i].glyph != b' ')
+            .unwrap_or(false)
+    }
+
+    fn cls(&mut self) {
+        for t in self.tiles.iter_mut() {
+            *t = Tile::default();
+        }
+    }
+
+    fn cls_bg(&mut self, background: RGB) {
+        for t in self.tiles.iter_mut() {
+            t.glyph = 32;
+            t.bg = background;
+        }
+    }
+
+    fn print(&mut self, x: i32, y: i32, output: &str) {
+        self.print_color(
+            x,
+            y,
+            RGB::from_f32(1.0, 1.0, 1.0),
+            RGB::from_f32(0.0, 0.0, 0.0),
+            output,
+        );
+    }
+
+    fn print_color(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, output: &str) {
+        for (i, c) in output.bytes().enumerate() {
+            self.set(x + i as i32, y, fg, bg, c);
+        }
+    }
+
+    fn set(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, glyph: u8) {
+        if let Some(i) = self.idx(x, y) {
+            self.tiles[i] = Tile { glyph, fg, bg };
+        }
+    }
+
+    fn set_bg(&mut self, x: i32, y: i32, bg: RGB) {
+        if let Some(i) = self.idx(x, y) {
+            self.tiles[i].bg = bg;
+        }
+    }
+
+    fn draw_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB) {
+        self.draw_hollow_box(x, y, width, height, fg, bg);
+    }
+
+    fn draw_box_double(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB) {
+        self.draw_hollow_box_double(x, y, width, height, fg, bg);
+    }
+
+    fn draw_hollow_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB) {
+        for cx in x..=x + width {
+            self.set(cx, y, fg, bg, b'-');
+            self.set(cx, y + height, fg, bg, b'-');
+        }
+        for cy in y..=y + height {
+            self.set(x, cy, fg, bg, b'|');
+            self.set(x + width, cy, fg, bg, b'|');
+        }
+    }
+
+    fn draw_hollow_box_double(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB) {
+        for cx in x..=x + width {
+            self.set(cx, y, fg, bg, b'=');
+            self.set(cx, y + height, fg, bg, b'=');
+        }
+        for cy in y..=y + height {
+            self.set(x, cy, fg, bg, b'"');
+            self.set(x + width, cy, fg, bg, b'"');
+        }
+    }
+
+    fn draw_bar_horizontal(&mut self, x: i32, y: i32, width: i32, n: i32, max: i32, fg: RGB, bg: RGB) {
+        let filled = if max == 0 { 0 } else { (n * width) / max };
+        for i in 0..width {
+            self.set(x + i, y, fg, bg, if i < filled { 178 } else { 176 });
+        }
+    }
+
+    fn draw_bar_vertical(&mut self, x: i32, y: i32, height: i32, n: i32, max: i32, fg: RGB, bg: RGB) {
+        let filled = if max == 0 { 0 } else { (n * height) / max };
+        for i in 0..height {
+            self.set(x, y + i, fg, bg, if i < filled { 178 } else { 176 });
+        }
+    }
+
+    fn fill_region(&mut self, target: Rect, glyph: u8, fg: RGB, bg: RGB) {
+        for y in target.y1..target.y2 {
+            for x in target.x1..target.x2 {
+                self.set(x, y, fg, bg, glyph);
+            }
+        }
+    }
+
+    fn print_centered(&mut self, y: i32, text: &str) {
+        let x = (self.width as i32 - text.len() as i32) / 2;
+        self.print(x, y, text);
+    }
+
+    fn print_color_centered(&mut self, y: i32, fg: RGB, bg: RGB, text: &str) {
+        let x = (self.width as i32 - text.len() as i32) / 2;
+        self.print_color(x, y, fg, bg, text);
+    }
+
+    fn print_centered_at(&mut self, x: i32, y: i32, text: &str) {
+        self.print(x - text.len() as i32 / 2, y, text);
+    }
+
+    fn print_color_centered_at(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: &str) {
+        self.print_color(x - text.len() as i32 / 2, y, fg, bg, text);
+    }
+
+    fn print_right(&mut self, x: i32, y: i32, text: &str) {
+        self.print(x - text.len() as i32, y, text);
+    }
+
+    fn print_color_right(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: &str) {
+        self.print_color(x - text.len() as i32, y, fg, bg, text);
+    }
+
+    /// Prints `output` as-is at `(x, y)`: inline `#[color]` markup is not parsed, and
+    /// `align` and `background` are ignored.
+    fn printer(&mut self, x: i32, y: i32, output: &str, _align: TextAlign, _background: Option<RGB>) {
+        self.print(x, y, output);
+    }
+
+    fn to_xp_layer(&self) -> XpLayer {
+        let mut layer = XpLayer::new(self.width as usize, self.height as usize);
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                if let Some(i) = self.idx(x, y) {
+                    let tile = &self.tiles[i];
+                    layer.set(x as usize, y as usize, tile.glyph, tile.fg, tile.bg);
+                }
+            }
+        }
+        layer
+    }
+
+    fn set_offset(&mut self, x: f32, y: f32) {
+        self.offset = (x, y);
+    }
+
+    fn get_offset(&self) -> (f32, f32) {
+        self.offset
+    }
+
+    fn set_scale(&mut self, scale: f32, center_x: i32, center_y: i32) {
+        self.scale = (scale, center_x, center_y);
+    }
+
+    fn get_scale(&self) -> (f32, i32, i32) {
+        self.scale
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_cells_are_not_opaque() {
+        let console = SimpleConsole::init(4, 4);
+        assert!(!console.is_cell_opaque(0, 0));
+    }
+
+    #[test]
+    fn painted_cells_are_opaque() {
+        let mut console = SimpleConsole::init(4, 4);
+        console.set(1, 1, RGB::from_f32(1.0, 1.0, 1.0), RGB::from_f32(0.0, 0.0, 0.0), b'@');
+        assert!(console.is_cell_opaque(1, 1));
+        assert!(!console.is_cell_opaque(0, 0));
+    }
+
+    #[test]
+    fn out_of_bounds_is_not_opaque() {
+        let console = SimpleConsole::init(4, 4);
+        assert!(!console.is_cell_opaque(-1, 0));
+        assert!(!console.is_cell_opaque(10, 10));
+    }
+}